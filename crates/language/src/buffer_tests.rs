@@ -359,6 +359,59 @@ async fn test_apply_diff(cx: &mut TestAppContext) {
     });
 }
 
+#[gpui::test]
+async fn test_apply_multiple_diffs(cx: &mut TestAppContext) {
+    let text = "one\ntwo\nthree\nfour\nfive\n";
+    let buffer =
+        cx.new_model(|cx| Buffer::new(0, BufferId::new(cx.entity_id().as_u64()).unwrap(), text));
+
+    // Apply several diffs in sequence, each computed against the latest version.
+    let mut versions = vec![buffer.update(cx, |buffer, _| buffer.version())];
+    let mut transaction_ids = Vec::new();
+    for text in [
+        "one\nTWO\nthree\nfour\nfive\n",
+        "one\nTWO\nthree\nfour\nfive\nsix\n",
+        "zero\none\nTWO\nfour\nfive\nsix\n",
+    ] {
+        let diff = buffer.update(cx, |b, cx| b.diff(text.into(), cx)).await;
+        buffer.update(cx, |buffer, cx| {
+            assert_eq!(diff.base_version, *versions.last().unwrap());
+            transaction_ids.push(buffer.apply_diff(diff, cx).unwrap());
+            assert_eq!(buffer.text(), text);
+            assert!(buffer.version().changed_since(versions.last().unwrap()));
+            versions.push(buffer.version());
+        });
+    }
+    transaction_ids.dedup();
+    assert_eq!(transaction_ids.len(), 3);
+
+    // Diffs computed against the same base still compose when their hunks don't overlap.
+    let first_diff = buffer
+        .update(cx, |b, cx| {
+            b.diff("ZERO\none\nTWO\nfour\nfive\nsix\n".into(), cx)
+        })
+        .await;
+    let second_diff = buffer
+        .update(cx, |b, cx| {
+            b.diff("zero\none\nTWO\nfour\nfive\nSIX\n".into(), cx)
+        })
+        .await;
+    assert_eq!(first_diff.base_version, second_diff.base_version);
+    buffer.update(cx, |buffer, cx| {
+        buffer.apply_diff(first_diff, cx).unwrap();
+        let version_after_first = buffer.version();
+        buffer.apply_diff(second_diff, cx).unwrap();
+        assert_eq!(buffer.text(), "ZERO\none\nTWO\nfour\nfive\nSIX\n");
+        assert!(buffer.version().changed_since(&version_after_first));
+    });
+
+    // Undoing the last transaction only reverts the most recent diff.
+    buffer.update(cx, |buffer, cx| {
+        buffer.undo(cx);
+        assert_eq!(buffer.text(), "ZERO\none\nTWO\nfour\nfive\nsix\n");
+    });
+}
+
 #[gpui::test(iterations = 10)]
 async fn test_normalize_whitespace(cx: &mut gpui::TestAppContext) {
     let text = [